@@ -0,0 +1,68 @@
+use glam::{Mat4, Vec3};
+
+/// A perspective camera that can recompute its model-view-projection
+/// matrix on demand.
+#[derive(Clone)]
+pub struct Camera {
+    pub position: Vec3,
+    pub target: Vec3,
+    pub fov_y_radians: f32,
+    pub aspect_ratio: f32,
+    pub near: f32,
+    pub far: f32,
+}
+
+impl Camera {
+    pub fn new(position: Vec3, target: Vec3, fov_y_radians: f32, aspect_ratio: f32) -> Camera {
+        Camera {
+            position,
+            target,
+            fov_y_radians,
+            aspect_ratio,
+            near: 0.1,
+            far: 100.0,
+        }
+    }
+
+    fn view_matrix(&self) -> Mat4 {
+        Mat4::look_at_rh(self.position, self.target, Vec3::Y)
+    }
+
+    fn projection_matrix(&self) -> Mat4 {
+        Mat4::perspective_rh_gl(self.fov_y_radians, self.aspect_ratio, self.near, self.far)
+    }
+
+    /// Builds the full model-view-projection matrix for `model`.
+    pub fn mvp(&self, model: Mat4) -> Mat4 {
+        self.projection_matrix() * self.view_matrix() * model
+    }
+
+    /// Moves both the eye and the look-at target by `delta`, preserving
+    /// the current view direction.
+    pub fn translate(&mut self, delta: Vec3) {
+        self.position += delta;
+        self.target += delta;
+    }
+
+    /// Rotates the view direction around the world up axis by `radians`,
+    /// keeping the eye position fixed.
+    pub fn yaw(&mut self, radians: f32) {
+        let offset = self.target - self.position;
+        let rotated = Mat4::from_rotation_y(radians).transform_vector3(offset);
+        self.target = self.position + rotated;
+    }
+
+    /// Blends between two fixed-update states by `alpha` (0.0 = `previous`,
+    /// 1.0 = `current`), so rendering can run at a different rate than the
+    /// fixed simulation step without visible stepping.
+    pub fn interpolate(previous: &Camera, current: &Camera, alpha: f32) -> Camera {
+        Camera {
+            position: previous.position.lerp(current.position, alpha),
+            target: previous.target.lerp(current.target, alpha),
+            fov_y_radians: current.fov_y_radians,
+            aspect_ratio: current.aspect_ratio,
+            near: current.near,
+            far: current.far,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::fs;
+use std::time::SystemTime;
+
+use crate::shader::ShaderProgram;
+
+/// Wraps a `ShaderProgram` and recompiles it whenever the backing GLSL
+/// files on disk change, so shader iteration doesn't require restarting
+/// the app. A failed recompile keeps the previously working program and
+/// just prints the info log.
+pub struct HotReloadShader {
+    program: ShaderProgram,
+    vertex_path: String,
+    fragment_path: String,
+    vertex_modified: SystemTime,
+    fragment_modified: SystemTime,
+}
+
+impl HotReloadShader {
+    pub fn new(
+        gl: &glow::Context,
+        vertex_path: &str,
+        fragment_path: &str,
+    ) -> Result<HotReloadShader, Box<dyn std::error::Error>> {
+        let vertex_src = fs::read_to_string(vertex_path)?;
+        let fragment_src = fs::read_to_string(fragment_path)?;
+        let program = ShaderProgram::new(gl, &vertex_src, &fragment_src)?;
+
+        Ok(HotReloadShader {
+            program,
+            vertex_path: vertex_path.to_string(),
+            fragment_path: fragment_path.to_string(),
+            vertex_modified: modified_time(vertex_path),
+            fragment_modified: modified_time(fragment_path),
+        })
+    }
+
+    pub fn program(&self) -> &ShaderProgram {
+        &self.program
+    }
+
+    /// Checks both source files' modification times and, if either
+    /// changed, recompiles and relinks in place. Call once per frame.
+    pub fn poll(&mut self, gl: &glow::Context) {
+        let vertex_modified = modified_time(&self.vertex_path);
+        let fragment_modified = modified_time(&self.fragment_path);
+
+        if vertex_modified <= self.vertex_modified && fragment_modified <= self.fragment_modified {
+            return;
+        }
+
+        self.vertex_modified = vertex_modified;
+        self.fragment_modified = fragment_modified;
+
+        let (vertex_src, fragment_src) = match (
+            fs::read_to_string(&self.vertex_path),
+            fs::read_to_string(&self.fragment_path),
+        ) {
+            (Ok(v), Ok(f)) => (v, f),
+            (Err(e), _) | (_, Err(e)) => {
+                eprintln!("hot reload: failed to read shader source: {}", e);
+                return;
+            }
+        };
+
+        match ShaderProgram::new(gl, &vertex_src, &fragment_src) {
+            Ok(program) => {
+                std::mem::replace(&mut self.program, program).delete(gl);
+            }
+            Err(e) => eprintln!("hot reload: keeping previous shader program:\n{}", e),
+        }
+    }
+}
+
+fn modified_time(path: &str) -> SystemTime {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .unwrap_or(SystemTime::UNIX_EPOCH)
+}
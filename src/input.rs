@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+use sdl2::event::Event;
+use sdl2::keyboard::Scancode;
+
+/// Tracks which keys are currently held down across frames, built from
+/// `KeyDown`/`KeyUp` events rather than polled once per press.
+pub struct InputState {
+    held: HashSet<Scancode>,
+}
+
+impl InputState {
+    pub fn new() -> InputState {
+        InputState {
+            held: HashSet::new(),
+        }
+    }
+
+    pub fn handle_event(&mut self, event: &Event) {
+        match event {
+            Event::KeyDown {
+                scancode: Some(scancode),
+                ..
+            } => {
+                self.held.insert(*scancode);
+            }
+            Event::KeyUp {
+                scancode: Some(scancode),
+                ..
+            } => {
+                self.held.remove(scancode);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn is_down(&self, scancode: Scancode) -> bool {
+        self.held.contains(&scancode)
+    }
+}
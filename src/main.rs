@@ -1,13 +1,30 @@
 extern crate sdl2;
 extern crate gl;
 
+mod camera;
+mod hotreload;
+mod input;
+mod shader;
+mod texture;
+mod timestep;
+
+use camera::Camera;
+use glam::{Mat4, Vec3};
 use glow::HasContext;
-use sdl2::event::Event;
-//use sdl2::keyboard::Keycode;
+use hotreload::HotReloadShader;
+use input::InputState;
+use sdl2::event::{Event, WindowEvent};
+use sdl2::keyboard::Scancode;
 //use sdl2::pixels::Color;
-use sdl2::sys::exit;
-use std::fs;
+use std::env;
 use std::time::Duration;
+use texture::Texture;
+use timestep::TimeStep;
+
+const MOVE_SPEED: f32 = 2.0;
+const TURN_SPEED: f32 = 1.5;
+
+const FIXED_DT: Duration = Duration::from_nanos(16_670_000);
 
 fn main() {
     let sdl2_context = sdl2::init().unwrap();
@@ -16,6 +33,7 @@ fn main() {
     let window = sdl2_video_context.window("SDL2+OpenGL Rust", 800, 500)
         .opengl()
         .position_centered()
+        .resizable()
         .build()
         .unwrap();
 
@@ -30,72 +48,139 @@ fn main() {
     let _gl_context = window.gl_create_context().unwrap();
     let gl = unsafe { glow::Context::from_loader_function(|s| sdl2_video_context.gl_get_proc_address(s) as *const _) };
 
+    // Caps the render rate to the display's refresh rate so the event/render
+    // loop doesn't busy-spin a CPU core now that the sleep-based throttle is gone.
+    sdl2_video_context
+        .gl_set_swap_interval(sdl2::video::SwapInterval::VSync)
+        .unwrap_or_else(|e| println!("vsync unavailable, falling back to immediate swap: {}", e));
+
     unsafe {
-        let vertices : [f32; 9] = [
-            -0.5, -0.5, 0.0,
-            0.5, -0.5, 0.0,
-            0.0, 0.5, 0.0
+        // pos.xyz, uv.xy interleaved, drawn as a triangle strip quad.
+        let vertices : [f32; 20] = [
+            -0.5, -0.5, 0.0,  0.0, 0.0,
+             0.5, -0.5, 0.0,  1.0, 0.0,
+            -0.5,  0.5, 0.0,  0.0, 1.0,
+             0.5,  0.5, 0.0,  1.0, 1.0,
         ];
 
-        let vertex_shader_source = fs::read_to_string("src/vertex.glsl").unwrap();
-        let vertex_shader = gl.create_shader(glow::VERTEX_SHADER).unwrap();
-        gl.shader_source(vertex_shader, &vertex_shader_source);
-        gl.compile_shader(vertex_shader);
-
-        let fragment_shader_source = fs::read_to_string("src/fragment.glsl").unwrap();
-        let fragment_shader = gl.create_shader(glow::FRAGMENT_SHADER).unwrap();
-        gl.shader_source(fragment_shader, &fragment_shader_source);
-        gl.compile_shader(fragment_shader);
-        
-
-        let program = gl.create_program().unwrap();
-        gl.attach_shader(program, vertex_shader);
-        gl.attach_shader(program, fragment_shader);
-        gl.link_program(program);
-        gl.use_program(Some(program));
+        let hot_reload = env::var("HOT_RELOAD_SHADERS").is_ok();
+        let mut shader =
+            HotReloadShader::new(&gl, "src/vertex.glsl", "src/fragment.glsl")
+                .unwrap_or_else(|e| panic!("{}", e));
+        shader.program().use_program(&gl);
 
         let vbo = gl.create_buffer().unwrap();
         gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
-        gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, &vertices.align_to().1, glow::STATIC_DRAW);
+        gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, vertices.align_to().1, glow::STATIC_DRAW);
+
+        let pos_attr = shader.program().attr("pos").expect("vertex shader has no `pos` attribute");
+        let uv_attr = shader.program().attr("uv").expect("vertex shader has no `uv` attribute");
+        let stride = 5 * std::mem::size_of::<f32>() as i32;
 
         let vao = gl.create_vertex_array().unwrap();
         gl.bind_vertex_array(Some(vao));
-        gl.enable_vertex_attrib_array(0);
+        gl.enable_vertex_attrib_array(pos_attr);
+        gl.vertex_attrib_pointer_f32(pos_attr, 3, glow::FLOAT, false, stride, 0);
+        gl.enable_vertex_attrib_array(uv_attr);
         gl.vertex_attrib_pointer_f32(
-            0,                     
-            3,                     
-            glow::FLOAT,            
-            false,                 
-            3 * std::mem::size_of::<f32>() as i32, 
-            0                       
+            uv_attr,
+            2,
+            glow::FLOAT,
+            false,
+            stride,
+            3 * std::mem::size_of::<f32>() as i32,
         );
 
+        let texture = Texture::load(&gl, "src/texture.png").unwrap_or_else(|e| {
+            println!("failed to load src/texture.png, using a placeholder: {}", e);
+            Texture::checkerboard(&gl, 4)
+        });
+        if let Some(uni_texture) = shader.program().uniform("uni_texture") {
+            gl.uniform_1_i32(Some(uni_texture), 0);
+        }
+
+        let mut camera = Camera::new(
+            Vec3::new(0.0, 0.0, 3.0),
+            Vec3::ZERO,
+            45.0_f32.to_radians(),
+            800.0 / 500.0,
+        );
+        let model = Mat4::IDENTITY;
+
         gl.viewport(0, 0, 800, 500);
         gl.clear_color(0.1, 0.1, 0.1, 1.0);
 
 
         let mut event_pump = sdl2_context.event_pump().unwrap();
+        let mut time_step = TimeStep::new(FIXED_DT);
+        let mut input = InputState::new();
+        let mut running = true;
 
-        loop {
-            for event in event_pump.poll_iter(){
-                println!("{:?}", event);
+        while running {
+            for event in event_pump.poll_iter() {
+                input.handle_event(&event);
 
                 match event {
                     Event::Quit { timestamp } => {
                         println!("{:?}", timestamp);
                         println!("QUIT");
-                         exit(0);
+                        running = false;
+                    }
+
+                    Event::KeyDown { scancode: Some(Scancode::Escape), .. } => {
+                        running = false;
+                    }
+
+                    Event::Window { win_event: WindowEvent::Resized(w, h), .. }
+                    | Event::Window { win_event: WindowEvent::SizeChanged(w, h), .. } => {
+                        gl.viewport(0, 0, w, h);
+                        camera.aspect_ratio = w as f32 / h as f32;
                     }
 
                     _ => {}
                 }
+            }
 
-                gl.clear(glow::COLOR_BUFFER_BIT);
-                gl.draw_arrays(glow::TRIANGLES, 0, 3);
-                window.gl_swap_window();
+            if hot_reload {
+                shader.poll(&gl);
+                shader.program().use_program(&gl);
+            }
+
+            let previous_camera = camera.clone();
+
+            time_step.begin_frame();
+            while time_step.step() {
+                let dt = FIXED_DT.as_secs_f32();
+                let forward = (camera.target - camera.position).normalize();
+                let right = forward.cross(Vec3::Y).normalize();
+
+                let mut movement = Vec3::ZERO;
+                if input.is_down(Scancode::W) || input.is_down(Scancode::Up) { movement += forward; }
+                if input.is_down(Scancode::S) || input.is_down(Scancode::Down) { movement -= forward; }
+                if input.is_down(Scancode::A) { movement -= right; }
+                if input.is_down(Scancode::D) { movement += right; }
+                if movement != Vec3::ZERO {
+                    camera.translate(movement.normalize() * MOVE_SPEED * dt);
+                }
 
-                ::std::thread::sleep(Duration::new(0, 1_000_000_000u32 / 60));
+                if input.is_down(Scancode::Left) {
+                    camera.yaw(TURN_SPEED * dt);
+                }
+                if input.is_down(Scancode::Right) {
+                    camera.yaw(-TURN_SPEED * dt);
+                }
             }
+            let render_camera = Camera::interpolate(&previous_camera, &camera, time_step.alpha());
+
+            if let Some(uni_mvp) = shader.program().uniform("uni_mvp") {
+                let mvp = render_camera.mvp(model);
+                gl.uniform_matrix_4_f32_slice(Some(uni_mvp), false, &mvp.to_cols_array());
+            }
+
+            texture.bind(&gl, 0);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+            gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+            window.gl_swap_window();
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use glow::HasContext;
+
+/// Error produced while compiling or linking a `ShaderProgram`, carrying the
+/// driver's info log so the caller can see exactly what went wrong.
+#[derive(Debug)]
+pub enum ShaderError {
+    Compile { stage: &'static str, log: String },
+    Link { log: String },
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShaderError::Compile { stage, log } => {
+                write!(f, "{} shader failed to compile:\n{}", stage, log)
+            }
+            ShaderError::Link { log } => write!(f, "program failed to link:\n{}", log),
+        }
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
+/// A linked GL program plus its uniform/attribute locations, cached by name
+/// so callers don't pay a driver round-trip on every frame.
+pub struct ShaderProgram {
+    program: glow::Program,
+    uniforms: HashMap<String, glow::UniformLocation>,
+    attributes: HashMap<String, u32>,
+}
+
+impl ShaderProgram {
+    /// Compiles `vertex_src`/`fragment_src`, links them into a program, and
+    /// caches the locations of all active uniforms and attributes.
+    pub fn new(
+        gl: &glow::Context,
+        vertex_src: &str,
+        fragment_src: &str,
+    ) -> Result<ShaderProgram, ShaderError> {
+        unsafe {
+            let vertex_shader = compile_shader(gl, glow::VERTEX_SHADER, "vertex", vertex_src)?;
+            let fragment_shader =
+                compile_shader(gl, glow::FRAGMENT_SHADER, "fragment", fragment_src)?;
+
+            let program = gl.create_program().expect("failed to create program");
+            gl.attach_shader(program, vertex_shader);
+            gl.attach_shader(program, fragment_shader);
+            gl.link_program(program);
+
+            gl.delete_shader(vertex_shader);
+            gl.delete_shader(fragment_shader);
+
+            if !gl.get_program_link_status(program) {
+                let log = gl.get_program_info_log(program);
+                gl.delete_program(program);
+                return Err(ShaderError::Link { log });
+            }
+
+            let uniforms = cache_uniforms(gl, program);
+            let attributes = cache_attributes(gl, program);
+
+            Ok(ShaderProgram {
+                program,
+                uniforms,
+                attributes,
+            })
+        }
+    }
+
+    pub fn use_program(&self, gl: &glow::Context) {
+        unsafe {
+            gl.use_program(Some(self.program));
+        }
+    }
+
+    pub fn attr(&self, name: &str) -> Option<u32> {
+        self.attributes.get(name).copied()
+    }
+
+    pub fn uniform(&self, name: &str) -> Option<&glow::UniformLocation> {
+        self.uniforms.get(name)
+    }
+
+    /// Deletes the underlying GL program. Call this on a `ShaderProgram`
+    /// that's being replaced (e.g. by a hot-reloaded one) so it doesn't
+    /// leak for the life of the GL context.
+    pub fn delete(self, gl: &glow::Context) {
+        unsafe {
+            gl.delete_program(self.program);
+        }
+    }
+}
+
+unsafe fn compile_shader(
+    gl: &glow::Context,
+    kind: u32,
+    stage: &'static str,
+    source: &str,
+) -> Result<glow::Shader, ShaderError> {
+    let shader = gl.create_shader(kind).expect("failed to create shader");
+    gl.shader_source(shader, source);
+    gl.compile_shader(shader);
+
+    if !gl.get_shader_compile_status(shader) {
+        let log = gl.get_shader_info_log(shader);
+        gl.delete_shader(shader);
+        return Err(ShaderError::Compile { stage, log });
+    }
+
+    Ok(shader)
+}
+
+unsafe fn cache_uniforms(
+    gl: &glow::Context,
+    program: glow::Program,
+) -> HashMap<String, glow::UniformLocation> {
+    let count = gl.get_active_uniforms(program);
+    let mut uniforms = HashMap::with_capacity(count as usize);
+    for index in 0..count {
+        if let Some(active) = gl.get_active_uniform(program, index) {
+            if let Some(location) = gl.get_uniform_location(program, &active.name) {
+                uniforms.insert(active.name, location);
+            }
+        }
+    }
+    uniforms
+}
+
+unsafe fn cache_attributes(gl: &glow::Context, program: glow::Program) -> HashMap<String, u32> {
+    let count = gl.get_active_attributes(program);
+    let mut attributes = HashMap::with_capacity(count as usize);
+    for index in 0..count {
+        if let Some(active) = gl.get_active_attribute(program, index) {
+            if let Some(location) = gl.get_attrib_location(program, &active.name) {
+                attributes.insert(active.name, location);
+            }
+        }
+    }
+    attributes
+}
@@ -0,0 +1,80 @@
+use glow::HasContext;
+use image::GenericImageView;
+
+/// A single 2D GL texture loaded from an image file on disk.
+pub struct Texture {
+    texture: glow::Texture,
+}
+
+impl Texture {
+    /// Loads `path` through the `image` crate, uploads it as an RGBA8
+    /// texture, and sets up linear filtering with edge clamping.
+    pub fn load(gl: &glow::Context, path: &str) -> Result<Texture, image::ImageError> {
+        let img = image::open(path)?.flipv().to_rgba8();
+        let (width, height) = img.dimensions();
+        Ok(unsafe { Texture::upload(gl, width, height, img.as_raw()) })
+    }
+
+    /// Builds a procedural black/white checkerboard texture with `size`
+    /// squares per side, with no dependency on an asset file. Handy as a
+    /// placeholder until real art is added.
+    pub fn checkerboard(gl: &glow::Context, squares_per_side: u32) -> Texture {
+        let size = squares_per_side * 2;
+        let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+        for y in 0..size {
+            for x in 0..size {
+                let value = if (x + y) % 2 == 0 { 255 } else { 40 };
+                pixels.extend_from_slice(&[value, value, value, 255]);
+            }
+        }
+        unsafe { Texture::upload(gl, size, size, &pixels) }
+    }
+
+    unsafe fn upload(gl: &glow::Context, width: u32, height: u32, pixels: &[u8]) -> Texture {
+        let texture = gl.create_texture().expect("failed to create texture");
+        gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_WRAP_S,
+            glow::CLAMP_TO_EDGE as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_WRAP_T,
+            glow::CLAMP_TO_EDGE as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MIN_FILTER,
+            glow::LINEAR as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MAG_FILTER,
+            glow::LINEAR as i32,
+        );
+
+        gl.tex_image_2d(
+            glow::TEXTURE_2D,
+            0,
+            glow::RGBA as i32,
+            width as i32,
+            height as i32,
+            0,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            Some(pixels),
+        );
+
+        Texture { texture }
+    }
+
+    /// Binds this texture to `unit` (0-based, i.e. `GL_TEXTURE0 + unit`).
+    pub fn bind(&self, gl: &glow::Context, unit: u32) {
+        unsafe {
+            gl.active_texture(glow::TEXTURE0 + unit);
+            gl.bind_texture(glow::TEXTURE_2D, Some(self.texture));
+        }
+    }
+}
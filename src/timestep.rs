@@ -0,0 +1,55 @@
+use std::time::{Duration, Instant};
+
+/// Decouples simulation from rendering: `begin_frame` accumulates elapsed
+/// wall-clock time, `step` drains it in fixed-size chunks so updates run at
+/// a constant rate regardless of frame rate, and `alpha` exposes how far
+/// into the next step we are so rendering can interpolate.
+/// Upper bound on fixed steps queued by a single `begin_frame`, so a stall
+/// (e.g. the OS blocking the event pump while the window is being resized
+/// or dragged) can't force `step` to drain hundreds of updates in one frame.
+const MAX_PENDING_STEPS: u32 = 8;
+
+pub struct TimeStep {
+    fixed_dt: Duration,
+    accumulator: Duration,
+    last_instant: Instant,
+}
+
+impl TimeStep {
+    pub fn new(fixed_dt: Duration) -> TimeStep {
+        TimeStep {
+            fixed_dt,
+            accumulator: Duration::ZERO,
+            last_instant: Instant::now(),
+        }
+    }
+
+    /// Call once per outer loop iteration, before draining fixed steps.
+    pub fn begin_frame(&mut self) {
+        let now = Instant::now();
+        self.accumulator += now - self.last_instant;
+        self.last_instant = now;
+
+        let max_accumulator = self.fixed_dt * MAX_PENDING_STEPS;
+        if self.accumulator > max_accumulator {
+            self.accumulator = max_accumulator;
+        }
+    }
+
+    /// Consumes one fixed step from the accumulator if enough time has
+    /// built up. Call in a `while` loop to drain as many steps as needed.
+    pub fn step(&mut self) -> bool {
+        if self.accumulator >= self.fixed_dt {
+            self.accumulator -= self.fixed_dt;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Fraction (0.0..1.0) of a fixed step left over in the accumulator,
+    /// for interpolating render state between the last two updates.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.fixed_dt.as_secs_f32()
+    }
+}